@@ -1,8 +1,9 @@
 use crate::config::{Client, Response};
-use crate::ids::CustomerId;
+use crate::ids::{CheckoutSessionId, CustomerId, PriceId, ShippingRateId};
+use crate::params::{Expand, Expandable, List, Metadata, Timestamp};
 use crate::resources::{
     CheckoutSession, CheckoutSessionLocale, CheckoutSessionMode, CheckoutSessionSubmitType,
-    Currency,
+    Currency, Price,
 };
 use serde_derive::{Deserialize, Serialize};
 // See: https://stripe.com/docs/api/checkout/sessions/create
@@ -46,6 +47,14 @@ pub struct CreateCheckoutSession<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub billing_address_collection: Option<&'a str>,
 
+    /// When set, provides configuration for Checkout to collect a shipping address from a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address_collection: Option<CreateCheckoutSessionShippingAddressCollection<'a>>,
+
+    /// The shipping rate options to display to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<CreateCheckoutSessionShippingOptions<'a>>>,
+
     /// The line items, plans, or SKUs purchased by the customer.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line_items: Option<Vec<CheckoutSessionLineItem<'a>>>,
@@ -60,11 +69,16 @@ pub struct CreateCheckoutSession<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mode: Option<CheckoutSessionMode>,
 
-    // A subset of parameters to be passed to PaymentIntent creation for Checkout Sessions in payment mode
-    // TODO: payment_intent_data
+    /// A subset of parameters to be passed to PaymentIntent creation for Checkout
+    /// Sessions in `payment` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent_data: Option<CreateCheckoutSessionPaymentIntentData<'a>>,
+
+    /// A subset of parameters to be passed to SetupIntent creation for Checkout
+    /// Sessions in `setup` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_intent_data: Option<CreateCheckoutSessionSetupIntentData<'a>>,
 
-    // A subset of parameters to be passed to SetupIntent creation for Checkout Sessions in setup mode.
-    // TODO: setup_intent_data
     /// Describes the type of transaction being performed by Checkout in order
     /// to customize relevant text on the page, such as the submit button.
     /// `submit_type` can only be specified on Checkout Sessions using line
@@ -73,8 +87,408 @@ pub struct CreateCheckoutSession<'a> {
     /// Supported values are `auto`, `book`, `donate`, or `pay`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub submit_type: Option<CheckoutSessionSubmitType>,
-    // A subset of parameters to be passed to subscription creation for Checkout Sessions in subscription mode.
-    // TODO: subscription_data
+
+    /// A subset of parameters to be passed to subscription creation for Checkout
+    /// Sessions in `subscription` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<CreateCheckoutSessionSubscriptionData<'a>>,
+
+    /// Set of key-value pairs that you can attach to an object. This can be
+    /// useful for storing additional information about the object in a
+    /// structured format. It's also propagated to the `PaymentIntent` or
+    /// `Subscription` created by the session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Enables user redeemable promotion codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+
+    /// The coupon or promotion code to apply to this Session. Currently, only up to
+    /// one may be specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<CreateCheckoutSessionDiscounts<'a>>>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Settings for automatic tax lookup for this session and resulting payments,
+    /// invoices, and subscriptions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CreateCheckoutSessionAutomaticTax>,
+
+    /// The Unix timestamp at which the Checkout Session will expire.
+    ///
+    /// It can be anywhere from 30 minutes to 24 hours after Checkout Session creation.
+    /// By default, this value is 24 hours from creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Configure actions after a Checkout Session has expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_expiration: Option<CreateCheckoutSessionAfterExpiration>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionAutomaticTax {
+    /// Set to `true` to enable automatic taxes.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionAfterExpiration {
+    /// Configure a Checkout Session that can be used to recover an expired session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery: Option<CreateCheckoutSessionAfterExpirationRecovery>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionAfterExpirationRecovery {
+    /// Enables user redeemable promotion codes on the recovered Checkout Sessions.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+
+    /// If `true`, a recovery URL will be generated to recover this Checkout Session if it
+    /// expires before a successful transaction is completed.
+    pub enabled: bool,
+}
+
+/// The `after_expiration` data on a `CheckoutSession`.
+///
+/// `CheckoutSession::after_expiration` itself is declared as `Option<Self>` on the base
+/// `CheckoutSession` resource, which is generated in a separate file outside this diff; see
+/// `CheckoutSession::after_expiration_recovery` below for the accessor that reaches it from a
+/// retrieved session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpiration {
+    /// Configuration for the Checkout Session that can be used to recover this expired
+    /// session, present once the session has expired.
+    pub recovery: Option<CheckoutSessionAfterExpirationRecovery>,
+}
+
+/// The `after_expiration.recovery` data on a `CheckoutSession`, surfaced once the
+/// session has expired and recovery was enabled at creation time.
+///
+/// See [`CreateCheckoutSessionAfterExpirationRecovery`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpirationRecovery {
+    /// Enables user redeemable promotion codes on the recovered Checkout Sessions.
+    pub allow_promotion_codes: bool,
+
+    /// If `true`, a recovery URL will be generated to recover this Checkout Session if it
+    /// expires before a successful transaction is completed.
+    pub enabled: bool,
+
+    /// The timestamp at which the recovery URL will expire.
+    pub expires_at: Option<Timestamp>,
+
+    /// The ID of the Checkout Session for the original expired session, set once this
+    /// recovery session is created.
+    pub recovered_from: Option<CheckoutSessionId>,
+
+    /// URL that creates a new Checkout Session when clicked that recovers this expired session.
+    pub url: Option<String>,
+}
+
+/// Either a coupon or a promotion code to apply to a Checkout Session. Exactly one of the
+/// two is ever present, which this enum enforces at the type level instead of leaving it to
+/// caller discipline, matching [`CheckoutSessionLineItemPriceSource`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum CreateCheckoutSessionDiscountsSource<'a> {
+    /// The ID of the coupon to apply to this Session.
+    Coupon {
+        /// The ID of the coupon to apply to this Session.
+        coupon: &'a str,
+    },
+    /// The ID of a promotion code to apply to this Session.
+    PromotionCode {
+        /// The ID of a promotion code to apply to this Session.
+        promotion_code: &'a str,
+    },
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionDiscounts<'a> {
+    #[serde(flatten)]
+    source: CreateCheckoutSessionDiscountsSource<'a>,
+}
+
+impl<'a> CreateCheckoutSessionDiscounts<'a> {
+    /// Creates a discount that applies an existing coupon.
+    pub fn new_with_coupon(coupon: &'a str) -> Self {
+        CreateCheckoutSessionDiscounts {
+            source: CreateCheckoutSessionDiscountsSource::Coupon { coupon },
+        }
+    }
+
+    /// Creates a discount that applies an existing promotion code.
+    pub fn new_with_promotion_code(promotion_code: &'a str) -> Self {
+        CreateCheckoutSessionDiscounts {
+            source: CreateCheckoutSessionDiscountsSource::PromotionCode { promotion_code },
+        }
+    }
+
+    /// The ID of the coupon this discount applies, if it was built with
+    /// [`CreateCheckoutSessionDiscounts::new_with_coupon`].
+    pub fn coupon(&self) -> Option<&'a str> {
+        match self.source {
+            CreateCheckoutSessionDiscountsSource::Coupon { coupon } => Some(coupon),
+            CreateCheckoutSessionDiscountsSource::PromotionCode { .. } => None,
+        }
+    }
+
+    /// The ID of the promotion code this discount applies, if it was built with
+    /// [`CreateCheckoutSessionDiscounts::new_with_promotion_code`].
+    pub fn promotion_code(&self) -> Option<&'a str> {
+        match self.source {
+            CreateCheckoutSessionDiscountsSource::Coupon { .. } => None,
+            CreateCheckoutSessionDiscountsSource::PromotionCode { promotion_code } => {
+                Some(promotion_code)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateCheckoutSessionPaymentIntentData<'a> {
+    /// The amount of the application fee (if any) that will be requested to be
+    /// applied to the payment and transferred to the application owner's Stripe
+    /// account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+
+    /// Indicates that you intend to make future payments with the payment
+    /// method collected during checkout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>,
+
+    /// Extra information about the payment. This will appear on your customer's
+    /// statement when this payment succeeds in creating a charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+
+    /// Provides information about a card payment that customers see on their
+    /// statements. Concatenated with the prefix (shortened descriptor) or
+    /// statement descriptor that's set on the account to form the complete
+    /// statement descriptor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix: Option<&'a str>,
+
+    /// Set of key-value pairs that you can attach to an object. This can be
+    /// useful for storing additional information about the object in a
+    /// structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateCheckoutSessionSetupIntentData<'a> {
+    /// An arbitrary string attached to the object. Often useful for displaying
+    /// to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Set of key-value pairs that you can attach to an object. This can be
+    /// useful for storing additional information about the object in a
+    /// structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateCheckoutSessionSubscriptionData<'a> {
+    /// A non-negative decimal between 0 and 100, with at most two decimal
+    /// places. This represents the percentage of the subscription invoice total
+    /// that will be transferred to the application owner's Stripe account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_percent: Option<f64>,
+
+    /// The tax rates that will apply to any subscription item that does not have
+    /// `tax_rates` set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<&'a str>>,
+
+    /// Set of key-value pairs that you can attach to an object. This can be
+    /// useful for storing additional information about the object in a
+    /// structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Integer representing the number of trial period days before the
+    /// customer is charged for the first time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_period_days: Option<u32>,
+
+    /// Unix timestamp representing the end of the trial period the customer
+    /// will get before being charged for the first time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_end: Option<Timestamp>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionShippingAddressCollection<'a> {
+    /// An array of two-letter ISO country codes representing which countries Checkout should
+    /// provide as options for shipping locations.
+    pub allowed_countries: Vec<&'a str>,
+}
+
+/// Either an existing Shipping Rate to offer, or inline data to generate a new one. Exactly
+/// one of the two is ever present, which this enum enforces at the type level instead of
+/// leaving it to caller discipline, matching [`CheckoutSessionLineItemPriceSource`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum CreateCheckoutSessionShippingOptionsSource<'a> {
+    /// The ID of the Shipping Rate to use for this shipping option.
+    ShippingRate {
+        /// The ID of the Shipping Rate to use for this shipping option.
+        shipping_rate: ShippingRateId,
+    },
+    /// Parameters to be passed to Shipping Rate creation for this shipping option.
+    ShippingRateData {
+        /// Parameters to be passed to Shipping Rate creation for this shipping option.
+        shipping_rate_data: CreateCheckoutSessionShippingOptionsShippingRateData<'a>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionShippingOptions<'a> {
+    #[serde(flatten)]
+    source: CreateCheckoutSessionShippingOptionsSource<'a>,
+}
+
+impl<'a> CreateCheckoutSessionShippingOptions<'a> {
+    /// Creates a shipping option that references an existing Shipping Rate.
+    pub fn new_with_shipping_rate(shipping_rate: ShippingRateId) -> Self {
+        CreateCheckoutSessionShippingOptions {
+            source: CreateCheckoutSessionShippingOptionsSource::ShippingRate { shipping_rate },
+        }
+    }
+
+    /// Creates a shipping option that generates a new Shipping Rate object inline.
+    pub fn new_with_shipping_rate_data(
+        shipping_rate_data: CreateCheckoutSessionShippingOptionsShippingRateData<'a>,
+    ) -> Self {
+        CreateCheckoutSessionShippingOptions {
+            source: CreateCheckoutSessionShippingOptionsSource::ShippingRateData {
+                shipping_rate_data,
+            },
+        }
+    }
+
+    /// The ID of the Shipping Rate this option references, if it was built with
+    /// [`CreateCheckoutSessionShippingOptions::new_with_shipping_rate`].
+    pub fn shipping_rate(&self) -> Option<&ShippingRateId> {
+        match &self.source {
+            CreateCheckoutSessionShippingOptionsSource::ShippingRate { shipping_rate } => {
+                Some(shipping_rate)
+            }
+            CreateCheckoutSessionShippingOptionsSource::ShippingRateData { .. } => None,
+        }
+    }
+
+    /// The inline Shipping Rate data for this option, if it was built with
+    /// [`CreateCheckoutSessionShippingOptions::new_with_shipping_rate_data`].
+    pub fn shipping_rate_data(
+        &self,
+    ) -> Option<&CreateCheckoutSessionShippingOptionsShippingRateData<'a>> {
+        match &self.source {
+            CreateCheckoutSessionShippingOptionsSource::ShippingRate { .. } => None,
+            CreateCheckoutSessionShippingOptionsSource::ShippingRateData { shipping_rate_data } => {
+                Some(shipping_rate_data)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionShippingOptionsShippingRateData<'a> {
+    /// The name of the shipping rate, displayed to the customer.
+    pub display_name: &'a str,
+
+    /// Describes a fixed amount to charge for shipping.
+    pub fixed_amount: CreateCheckoutSessionShippingOptionsShippingRateDataFixedAmount,
+
+    /// The estimated range for how fast shipping will be processed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_estimate:
+        Option<CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimate>,
+
+    /// Specifies whether the rate is considered inclusive of taxes or exclusive of taxes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<CreateCheckoutSessionTaxBehavior>,
+}
+
+/// An enum representing the possible values of a Checkout Session price or shipping rate's
+/// `tax_behavior` field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateCheckoutSessionTaxBehavior {
+    Exclusive,
+    Inclusive,
+    Unspecified,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionShippingOptionsShippingRateDataFixedAmount {
+    /// A non-negative integer in cents representing how much to charge.
+    pub amount: i64,
+
+    /// Three-letter ISO currency code, in lowercase.
+    pub currency: Currency,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimate {
+    /// The upper bound of the estimated range. If empty, represents no upper bound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateBound>,
+
+    /// The lower bound of the estimated range. If empty, represents no lower bound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateBound>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateBound {
+    /// A unit of time.
+    pub unit: CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateBoundUnit,
+
+    /// Must be greater than 0.
+    pub value: i64,
+}
+
+/// An enum representing the possible values of an
+/// `CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateBound`'s `unit` field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateBoundUnit {
+    BusinessDay,
+    Day,
+    Hour,
+    Month,
+    Week,
+}
+
+/// Either an existing Price/Plan to bill against, or inline data to generate a new Price
+/// object. Exactly one of the two is ever present, which this enum enforces at the type
+/// level instead of leaving it to caller discipline.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum CheckoutSessionLineItemPriceSource<'a> {
+    /// The ID of the Price or Plan to add to the Checkout Session.
+    Price {
+        /// The ID of the Price or Plan to add to the Checkout Session.
+        price: PriceId,
+    },
+    /// Data used to generate a new Price object inline.
+    PriceData {
+        /// Data used to generate a new Price object inline.
+        price_data: CheckoutSessionLineItemPriceData<'a>,
+    },
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -82,8 +496,17 @@ pub struct CheckoutSessionLineItem<'a> {
     /// The quantity of the line item being purchased.
     pub quantity: u64,
 
-    /// Data used to generate a new Price object inline. One of price, price_data or amount is required.
-    pub price_data: CheckoutSessionLineItemPriceData<'a>,
+    /// The Price or Plan this line item bills against. One of `price` or `price_data` is
+    /// required; use [`CheckoutSessionLineItem::new_with_price`] or
+    /// [`CheckoutSessionLineItem::new_with_price_data`] to construct this, which keeps the
+    /// two mutually exclusive.
+    #[serde(flatten)]
+    price_source: CheckoutSessionLineItemPriceSource<'a>,
+
+    /// When set, provides configuration for the customer to adjust the quantity of the
+    /// line item created when a customer chooses to add a quantity selector in Checkout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjustable_quantity: Option<CheckoutSessionLineItemAdjustableQuantity>,
 
     /// The description for the line item.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,9 +521,77 @@ pub struct CheckoutSessionLineItem<'a> {
     /// all countries in the EU..
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dynamic_tax_rates: Option<Vec<&'a str>>,
+
+    /// The tax rates which apply to this line item. When set, the `dynamic_tax_rates` and
+    /// the Checkout Session's `automatic_tax` settings are ignored for this line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<&'a str>>,
     // TODO: remaining optional fields
 }
 
+impl<'a> CheckoutSessionLineItem<'a> {
+    /// Creates a line item that references an existing Price or Plan.
+    pub fn new_with_price(price: PriceId, quantity: u64) -> Self {
+        CheckoutSessionLineItem {
+            quantity,
+            price_source: CheckoutSessionLineItemPriceSource::Price { price },
+            adjustable_quantity: None,
+            description: None,
+            images: None,
+            dynamic_tax_rates: None,
+            tax_rates: None,
+        }
+    }
+
+    /// Creates a line item that generates a new Price object inline.
+    pub fn new_with_price_data(
+        price_data: CheckoutSessionLineItemPriceData<'a>,
+        quantity: u64,
+    ) -> Self {
+        CheckoutSessionLineItem {
+            quantity,
+            price_source: CheckoutSessionLineItemPriceSource::PriceData { price_data },
+            adjustable_quantity: None,
+            description: None,
+            images: None,
+            dynamic_tax_rates: None,
+            tax_rates: None,
+        }
+    }
+
+    /// The ID of the Price or Plan this line item references, if it was built with
+    /// [`CheckoutSessionLineItem::new_with_price`].
+    pub fn price(&self) -> Option<&PriceId> {
+        match &self.price_source {
+            CheckoutSessionLineItemPriceSource::Price { price } => Some(price),
+            CheckoutSessionLineItemPriceSource::PriceData { .. } => None,
+        }
+    }
+
+    /// The inline Price data for this line item, if it was built with
+    /// [`CheckoutSessionLineItem::new_with_price_data`].
+    pub fn price_data(&self) -> Option<&CheckoutSessionLineItemPriceData<'a>> {
+        match &self.price_source {
+            CheckoutSessionLineItemPriceSource::Price { .. } => None,
+            CheckoutSessionLineItemPriceSource::PriceData { price_data } => Some(price_data),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckoutSessionLineItemAdjustableQuantity {
+    /// Set to true if the quantity can be adjusted to any non-negative integer.
+    pub enabled: bool,
+
+    /// The maximum quantity the customer can purchase. By default this value is 99.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<i64>,
+
+    /// The minimum quantity the customer can purchase. By default this value is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<i64>,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct CheckoutSessionLineItemPriceData<'a> {
     /// The amount to be collected per unit of the line item.
@@ -114,6 +605,10 @@ pub struct CheckoutSessionLineItemPriceData<'a> {
 
     /// The product data.
     pub product_data: CheckoutSessionLineItemPriceDataProductData<'a>,
+
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<CreateCheckoutSessionTaxBehavior>,
 }
 #[derive(Clone, Debug, Serialize)]
 pub struct CheckoutSessionLineItemPriceDataProductData<'a> {
@@ -122,7 +617,72 @@ pub struct CheckoutSessionLineItemPriceDataProductData<'a> {
 
     /// The amount to be collected per unit of the line item.
     pub description: Option<&'a str>,
-    // TODO: images & metadata
+
+    /// Set of key-value pairs that you can attach to an object. This can be
+    /// useful for storing additional information about the object in a
+    /// structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<&'a str>,
+    // TODO: images
+}
+
+/// The parameters for `CheckoutSession::retrieve`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RetrieveCheckoutSession<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+/// The parameters for `CheckoutSession::list_line_items`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCheckoutSessionLineItems<'a> {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your
+    /// place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your
+    /// place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+/// A line item returned by `CheckoutSession::list_line_items`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionItem {
+    /// Unique identifier for the object.
+    pub id: String,
+
+    /// Total before any discounts or taxes are applied.
+    pub amount_subtotal: i64,
+
+    /// Total after discounts and taxes.
+    pub amount_total: i64,
+
+    /// Three-letter ISO currency code, in lowercase.
+    pub currency: Currency,
+
+    /// Description for the line item.
+    pub description: String,
+
+    /// The price used to generate the line item.
+    pub price: Option<Expandable<Price>>,
+
+    /// The quantity of products being purchased.
+    pub quantity: Option<u64>,
 }
 
 impl CheckoutSession {
@@ -132,4 +692,75 @@ impl CheckoutSession {
     pub fn create(client: &Client, params: CreateCheckoutSession) -> Response<CheckoutSession> {
         client.post_form("/checkout/sessions", params)
     }
+
+    /// Retrieves a Session object.
+    ///
+    /// For more details see [https://stripe.com/docs/api/checkout/sessions/retrieve](https://stripe.com/docs/api/checkout/sessions/retrieve).
+    pub fn retrieve(
+        client: &Client,
+        id: &CheckoutSessionId,
+        params: RetrieveCheckoutSession,
+    ) -> Response<CheckoutSession> {
+        client.get_query(&format!("/checkout/sessions/{}", id), &params)
+    }
+
+    /// When retrieving a Checkout Session, there is an includable `line_items` property
+    /// containing the first handful of those items. This method can be used to fetch the
+    /// full (paginated) list.
+    ///
+    /// For more details see [https://stripe.com/docs/api/checkout/sessions/line_items](https://stripe.com/docs/api/checkout/sessions/line_items).
+    pub fn list_line_items(
+        client: &Client,
+        id: &CheckoutSessionId,
+        params: ListCheckoutSessionLineItems,
+    ) -> Response<List<CheckoutSessionItem>> {
+        client.get_query(&format!("/checkout/sessions/{}/line_items", id), &params)
+    }
+
+    /// The recovery information for this Session, present only once the Session has expired
+    /// and recovery was enabled via `after_expiration.recovery.enabled` at creation time.
+    ///
+    /// Reads `self.after_expiration`, declared on the base `CheckoutSession` resource
+    /// (outside this diff) as `Option<CheckoutSessionAfterExpiration>`.
+    pub fn after_expiration_recovery(&self) -> Option<&CheckoutSessionAfterExpirationRecovery> {
+        self.after_expiration.as_ref()?.recovery.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `CheckoutSessionLineItem` is posted as form data (see `Client::post_form`), not JSON, so
+    // these assert against the form-encoded output rather than `serde_json`.
+
+    #[test]
+    fn line_item_with_price_serializes_only_price() {
+        let price = "price_123".parse::<PriceId>().unwrap();
+        let item = CheckoutSessionLineItem::new_with_price(price, 2);
+
+        let encoded = serde_qs::to_string(&item).unwrap();
+        assert!(encoded.contains("price=price_123"));
+        assert!(!encoded.contains("price_data"));
+    }
+
+    #[test]
+    fn line_item_with_price_data_serializes_only_price_data() {
+        let price_data = CheckoutSessionLineItemPriceData {
+            unit_amount: 1000,
+            currency: Currency::USD,
+            product_data: CheckoutSessionLineItemPriceDataProductData {
+                name: "Shirt",
+                description: None,
+                metadata: None,
+                tax_code: None,
+            },
+            tax_behavior: None,
+        };
+        let item = CheckoutSessionLineItem::new_with_price_data(price_data, 1);
+
+        let encoded = serde_qs::to_string(&item).unwrap();
+        assert!(encoded.contains("price_data"));
+        assert!(!encoded.contains("price="));
+    }
 }